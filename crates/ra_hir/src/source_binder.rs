@@ -5,19 +5,23 @@ use hir_def::{
     child_by_source::ChildBySource,
     dyn_map::DynMap,
     keys::{self, Key},
+    path::ModPath,
     resolver::{HasResolver, Resolver},
-    ConstId, DefWithBodyId, EnumId, EnumVariantId, FunctionId, GenericDefId, ImplId, ModuleId,
-    StaticId, StructFieldId, StructId, TraitId, TypeAliasId, UnionId, VariantId,
+    ConstId, ContainerId, DefWithBodyId, EnumId, EnumVariantId, FunctionId, GenericDefId, ImplId,
+    Lookup, ModuleId, StaticId, StructFieldId, StructId, TraitId, TypeAliasId, UnionId, VariantId,
 };
-use hir_expand::{name::AsName, AstId, InFile, MacroDefId, MacroDefKind};
+use hir_expand::{hygiene::Hygiene, name::AsName, AstId, InFile, MacroDefId, MacroDefKind};
 use ra_prof::profile;
 use ra_syntax::{
     ast::{self, NameOwner},
-    match_ast, AstNode, SyntaxNode, TextUnit,
+    match_ast, AstNode, SyntaxNode, TextUnit, WalkEvent,
 };
 use rustc_hash::FxHashMap;
 
-use crate::{db::HirDatabase, Local, Module, SourceAnalyzer, TypeParam};
+use crate::{
+    db::HirDatabase, DefWithBody, GenericDef, ImplBlock, Label, Local, LifetimeParam, Module,
+    ModuleDef, SourceAnalyzer, TypeParam, VariantDef,
+};
 use ra_db::FileId;
 
 pub struct SourceBinder<'a, DB> {
@@ -41,17 +45,10 @@ impl<DB: HirDatabase> SourceBinder<'_, DB> {
             None => return SourceAnalyzer::new_for_resolver(Resolver::default(), src),
         };
 
-        let resolver = match container {
-            ChildContainer::DefWithBodyId(def) => {
-                return SourceAnalyzer::new_for_body(self.db, def, src, offset)
-            }
-            ChildContainer::TraitId(it) => it.resolver(self.db),
-            ChildContainer::ImplId(it) => it.resolver(self.db),
-            ChildContainer::ModuleId(it) => it.resolver(self.db),
-            ChildContainer::EnumId(it) => it.resolver(self.db),
-            ChildContainer::VariantId(it) => it.resolver(self.db),
-            ChildContainer::GenericDefId(it) => it.resolver(self.db),
-        };
+        if let ChildContainer::DefWithBodyId(def) = container {
+            return SourceAnalyzer::new_for_body(self.db, def, src, offset);
+        }
+        let resolver = self.resolver_for_container(container);
         SourceAnalyzer::new_for_resolver(resolver, src)
     }
 
@@ -59,6 +56,12 @@ impl<DB: HirDatabase> SourceBinder<'_, DB> {
         T::to_def(self, src)
     }
 
+    /// The def (fn/struct/trait/module/impl/...) that most closely encloses `src`.
+    pub fn container_def(&mut self, src: InFile<&SyntaxNode>) -> Option<ContainerDef> {
+        let container = self.find_container(src)?;
+        self.container_to_def(container)
+    }
+
     pub fn to_module_def(&mut self, file: FileId) -> Option<Module> {
         let _p = profile("SourceBinder::to_module_def");
         let (krate, local_id) = self.db.relevant_crates(file).iter().find_map(|&crate_id| {
@@ -73,56 +76,158 @@ impl<DB: HirDatabase> SourceBinder<'_, DB> {
         T::to_id(self, src)
     }
 
+    /// Like `ToId::to_id` for a `ToIdByKey` type, but for when the caller already knows `src`'s
+    /// enclosing `ChildContainer` (eg. from a stack built while walking the
+    /// tree top-down) and so can skip the `find_container` ancestor walk
+    /// `to_id` would otherwise redo from scratch.
+    fn to_id_in_container<T: ToIdByKey>(
+        &mut self,
+        container: ChildContainer,
+        src: InFile<T>,
+    ) -> Option<T::ID> {
+        let dyn_map = self.child_by_source(container);
+        dyn_map[T::KEY].get(&src).copied()
+    }
+
+    /// Resolves `src` to its id, reusing `current` (its known enclosing
+    /// container) if present instead of rediscovering it via `find_container`.
+    fn to_id_with_container<T: ToIdByKey>(
+        &mut self,
+        current: Option<ChildContainer>,
+        src: InFile<T>,
+    ) -> Option<T::ID> {
+        match current {
+            Some(container) => self.to_id_in_container(container, src),
+            None => self.to_id(src),
+        }
+    }
+
     fn find_container(&mut self, src: InFile<&SyntaxNode>) -> Option<ChildContainer> {
         for container in src.cloned().ancestors_with_macros(self.db).skip(1) {
-            let res: ChildContainer = match_ast! {
-                match (container.value) {
-                    ast::TraitDef(it) => {
-                        let def: TraitId = self.to_id(container.with_value(it))?;
-                        def.into()
-                    },
-                    ast::ImplBlock(it) => {
-                        let def: ImplId = self.to_id(container.with_value(it))?;
-                        def.into()
-                    },
-                    ast::FnDef(it) => {
-                        let def: FunctionId = self.to_id(container.with_value(it))?;
-                        DefWithBodyId::from(def).into()
-                    },
-                    ast::StaticDef(it) => {
-                        let def: StaticId = self.to_id(container.with_value(it))?;
-                        DefWithBodyId::from(def).into()
-                    },
-                    ast::ConstDef(it) => {
-                        let def: ConstId = self.to_id(container.with_value(it))?;
-                        DefWithBodyId::from(def).into()
-                    },
-                    ast::EnumDef(it) => {
-                        let def: EnumId = self.to_id(container.with_value(it))?;
-                        def.into()
-                    },
-                    ast::StructDef(it) => {
-                        let def: StructId = self.to_id(container.with_value(it))?;
-                        VariantId::from(def).into()
-                    },
-                    ast::UnionDef(it) => {
-                        let def: UnionId = self.to_id(container.with_value(it))?;
-                        VariantId::from(def).into()
-                    },
-                    ast::Module(it) => {
-                        let def: ModuleId = self.to_id(container.with_value(it))?;
-                        def.into()
-                    },
-                    _ => { continue },
-                }
-            };
-            return Some(res);
+            if let Some(res) = self.container_for_node(None, container) {
+                return Some(res);
+            }
         }
 
         let c = self.to_module_def(src.file_id.original_file(self.db))?;
         Some(c.id.into())
     }
 
+    /// If `node` is itself one of the item kinds that introduces a new
+    /// `child_by_source` scope (fn/struct/trait/impl/module/...), returns the
+    /// `ChildContainer` for it. Shared by `find_container` (which walks
+    /// ancestors looking for the nearest one, and so has no container handy
+    /// for `node` itself -- pass `None`) and `analyze_file` (which already
+    /// knows `node`'s enclosing container from its stack, and passes it as
+    /// `current` so the lookup skips re-walking ancestors).
+    fn container_for_node(
+        &mut self,
+        current: Option<ChildContainer>,
+        container: InFile<SyntaxNode>,
+    ) -> Option<ChildContainer> {
+        match_ast! {
+            match (container.value) {
+                ast::TraitDef(it) => {
+                    let def: TraitId = self.to_id_with_container(current, container.with_value(it))?;
+                    Some(def.into())
+                },
+                ast::ImplBlock(it) => {
+                    let def: ImplId = self.to_id_with_container(current, container.with_value(it))?;
+                    Some(def.into())
+                },
+                ast::FnDef(it) => {
+                    let def: FunctionId = self.to_id_with_container(current, container.with_value(it))?;
+                    Some(DefWithBodyId::from(def).into())
+                },
+                ast::StaticDef(it) => {
+                    let def: StaticId = self.to_id_with_container(current, container.with_value(it))?;
+                    Some(DefWithBodyId::from(def).into())
+                },
+                ast::ConstDef(it) => {
+                    let def: ConstId = self.to_id_with_container(current, container.with_value(it))?;
+                    Some(DefWithBodyId::from(def).into())
+                },
+                ast::EnumDef(it) => {
+                    let def: EnumId = self.to_id_with_container(current, container.with_value(it))?;
+                    Some(def.into())
+                },
+                ast::StructDef(it) => {
+                    let def: StructId = self.to_id_with_container(current, container.with_value(it))?;
+                    Some(VariantId::from(def).into())
+                },
+                ast::UnionDef(it) => {
+                    let def: UnionId = self.to_id_with_container(current, container.with_value(it))?;
+                    Some(VariantId::from(def).into())
+                },
+                ast::Module(it) => {
+                    // Module lookup doesn't go through a `child_by_source`
+                    // `DynMap`, so there's no container-aware fast path here.
+                    let def: ModuleId = self.to_id(container.with_value(it))?;
+                    Some(def.into())
+                },
+                _ => None,
+            }
+        }
+    }
+
+    /// Resolves every node in `file` to its enclosing def in a single
+    /// top-down pass, reusing the container stack and cache across nodes
+    /// instead of re-walking ancestors per node like `container_def` does.
+    pub fn analyze_file(
+        &mut self,
+        file: FileId,
+    ) -> impl Iterator<Item = (InFile<SyntaxNode>, ContainerDef)> + '_ {
+        let _p = profile("SourceBinder::analyze_file");
+        let root = self.db.parse(file).tree().syntax().clone();
+        let file_id = file.into();
+
+        let top_level = self.to_module_def(file).map(|it| ChildContainer::from(it.id));
+        let mut stack: Vec<Option<ChildContainer>> = vec![top_level];
+        let mut events = root.preorder();
+
+        std::iter::from_fn(move || loop {
+            match events.next()? {
+                WalkEvent::Enter(node) => {
+                    let src = InFile { file_id, value: node };
+                    let current = *stack.last().unwrap();
+                    let child = self.container_for_node(current, src.clone()).or(current);
+                    stack.push(child);
+                    if let Some(def) = current.and_then(|c| self.container_to_def(c)) {
+                        return Some((src, def));
+                    }
+                }
+                WalkEvent::Leave(_) => {
+                    stack.pop();
+                }
+            }
+        })
+    }
+
+    fn container_to_def(&mut self, container: ChildContainer) -> Option<ContainerDef> {
+        let def = match container {
+            ChildContainer::DefWithBodyId(it) => ContainerDef::DefWithBody(it.into()),
+            ChildContainer::ModuleId(it) => ContainerDef::ModuleDef(it.into()),
+            ChildContainer::TraitId(it) => ContainerDef::ModuleDef(it.into()),
+            ChildContainer::ImplId(it) => ContainerDef::ImplBlock(it.into()),
+            ChildContainer::EnumId(it) => ContainerDef::ModuleDef(it.into()),
+            ChildContainer::VariantId(it) => ContainerDef::VariantDef(it.into()),
+            ChildContainer::GenericDefId(it) => ContainerDef::GenericDef(it.into()),
+        };
+        Some(def)
+    }
+
+    fn resolver_for_container(&self, container: ChildContainer) -> Resolver {
+        match container {
+            ChildContainer::DefWithBodyId(it) => it.resolver(self.db),
+            ChildContainer::TraitId(it) => it.resolver(self.db),
+            ChildContainer::ImplId(it) => it.resolver(self.db),
+            ChildContainer::ModuleId(it) => it.resolver(self.db),
+            ChildContainer::EnumId(it) => it.resolver(self.db),
+            ChildContainer::VariantId(it) => it.resolver(self.db),
+            ChildContainer::GenericDefId(it) => it.resolver(self.db),
+        }
+    }
+
     fn child_by_source(&mut self, container: ChildContainer) -> &DynMap {
         let db = self.db;
         self.child_by_source_cache.entry(container).or_insert_with(|| match container {
@@ -178,6 +283,17 @@ to_def_impls![
     (crate::MacroDef, ast::MacroCall), // this one is dubious, not all calls are macros
 ];
 
+/// The public counterpart of `ChildContainer`: the definition enclosing a
+/// piece of source code, returned by `SourceBinder::container_def`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerDef {
+    DefWithBody(DefWithBody),
+    ModuleDef(ModuleDef),
+    ImplBlock(ImplBlock),
+    VariantDef(VariantDef),
+    GenericDef(GenericDef),
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 enum ChildContainer {
     DefWithBodyId(DefWithBodyId),
@@ -213,18 +329,7 @@ impl<T: ToIdByKey> ToId for T {
         src: InFile<Self>,
     ) -> Option<Self::ID> {
         let container = sb.find_container(src.as_ref().map(|it| it.syntax()))?;
-        let db = sb.db;
-        let dyn_map =
-            &*sb.child_by_source_cache.entry(container).or_insert_with(|| match container {
-                ChildContainer::DefWithBodyId(it) => it.child_by_source(db),
-                ChildContainer::ModuleId(it) => it.child_by_source(db),
-                ChildContainer::TraitId(it) => it.child_by_source(db),
-                ChildContainer::ImplId(it) => it.child_by_source(db),
-                ChildContainer::EnumId(it) => it.child_by_source(db),
-                ChildContainer::VariantId(it) => it.child_by_source(db),
-                ChildContainer::GenericDefId(it) => it.child_by_source(db),
-            });
-        dyn_map[T::KEY].get(&src).copied()
+        sb.to_id_in_container(container, src)
     }
 }
 
@@ -251,6 +356,118 @@ to_id_key_impls![
     (EnumVariantId, ast::EnumVariant, keys::ENUM_VARIANT),
 ];
 
+impl From<ContainerId> for ChildContainer {
+    fn from(c: ContainerId) -> ChildContainer {
+        match c {
+            ContainerId::ModuleId(it) => it.into(),
+            ContainerId::ImplId(it) => it.into(),
+            ContainerId::TraitId(it) => it.into(),
+        }
+    }
+}
+
+/// Inverse of `ToId`: maps a def's id back to all of its source declarations.
+/// Implemented for the `hir_def`/`hir_expand` ids (`StructId`, `ModuleId`,
+/// `MacroDefId`, ...), not for the `crate::` types that `ToDef` returns.
+pub trait HasSource: Sized {
+    type Ast;
+    fn source<DB: HirDatabase>(self, sb: &mut SourceBinder<'_, DB>) -> Vec<InFile<Self::Ast>>;
+}
+
+macro_rules! has_source_in_module_impls {
+    ($(($id:ident, $ast:path, $key:path)),* ,) => {$(
+        impl HasSource for $id {
+            type Ast = $ast;
+            fn source<DB: HirDatabase>(self, sb: &mut SourceBinder<'_, DB>) -> Vec<InFile<Self::Ast>> {
+                let container = ChildContainer::ModuleId(self.lookup(sb.db).container);
+                sb.child_by_source(container)[$key]
+                    .iter()
+                    .filter(|(_src, &id)| id == self)
+                    .map(|(src, _id)| src.clone())
+                    .collect()
+            }
+        }
+    )*}
+}
+
+has_source_in_module_impls![
+    (StructId, ast::StructDef, keys::STRUCT),
+    (UnionId, ast::UnionDef, keys::UNION),
+    (EnumId, ast::EnumDef, keys::ENUM),
+    (TraitId, ast::TraitDef, keys::TRAIT),
+    (TypeAliasId, ast::TypeAliasDef, keys::TYPE_ALIAS),
+    (ImplId, ast::ImplBlock, keys::IMPL),
+];
+
+macro_rules! has_source_in_item_container_impls {
+    ($(($id:ident, $ast:path, $key:path)),* ,) => {$(
+        impl HasSource for $id {
+            type Ast = $ast;
+            fn source<DB: HirDatabase>(self, sb: &mut SourceBinder<'_, DB>) -> Vec<InFile<Self::Ast>> {
+                let container = self.lookup(sb.db).container.into();
+                sb.child_by_source(container)[$key]
+                    .iter()
+                    .filter(|(_src, &id)| id == self)
+                    .map(|(src, _id)| src.clone())
+                    .collect()
+            }
+        }
+    )*}
+}
+
+has_source_in_item_container_impls![
+    (FunctionId, ast::FnDef, keys::FUNCTION),
+    (ConstId, ast::ConstDef, keys::CONST),
+    (StaticId, ast::StaticDef, keys::STATIC),
+];
+
+impl HasSource for EnumVariantId {
+    type Ast = ast::EnumVariant;
+    fn source<DB: HirDatabase>(self, sb: &mut SourceBinder<'_, DB>) -> Vec<InFile<Self::Ast>> {
+        let container = ChildContainer::EnumId(self.parent);
+        sb.child_by_source(container)[keys::ENUM_VARIANT]
+            .iter()
+            .filter(|(_src, &id)| id == self)
+            .map(|(src, _id)| src.clone())
+            .collect()
+    }
+}
+
+impl HasSource for ModuleId {
+    type Ast = ast::Module;
+    fn source<DB: HirDatabase>(self, sb: &mut SourceBinder<'_, DB>) -> Vec<InFile<Self::Ast>> {
+        // The crate root isn't declared by a `mod` item anywhere -- it has no
+        // `ast::Module` source, just the file itself.
+        let def_map = sb.db.crate_def_map(self.krate);
+        let parent_local_id = match def_map[self.local_id].parent {
+            Some(it) => it,
+            None => return Vec::new(),
+        };
+        let parent = ModuleId { krate: self.krate, local_id: parent_local_id };
+        let container = ChildContainer::ModuleId(parent);
+        sb.child_by_source(container)[keys::MODULE]
+            .iter()
+            .filter(|(_src, &id)| id == self)
+            .map(|(src, _id)| src.clone())
+            .collect()
+    }
+}
+
+// `MacroDefId` only carries a single optional `ast_id`, not a set of
+// declarations indexed by a `child_by_source` `DynMap` (a macro can't be
+// declared in several `#[cfg]` arms the way an item can), so this resolves
+// the one `ast_id` directly instead of going through `HasSource`'s usual
+// `DynMap` machinery.
+impl HasSource for MacroDefId {
+    type Ast = ast::MacroCall;
+    fn source<DB: HirDatabase>(self, sb: &mut SourceBinder<'_, DB>) -> Vec<InFile<Self::Ast>> {
+        match self.ast_id {
+            Some(ast_id) => vec![InFile { file_id: ast_id.file_id, value: ast_id.to_node(sb.db) }],
+            None => Vec::new(),
+        }
+    }
+}
+
 // FIXME: use DynMap as well?
 impl ToId for ast::MacroCall {
     type ID = MacroDefId;
@@ -258,14 +475,30 @@ impl ToId for ast::MacroCall {
         sb: &mut SourceBinder<'_, DB>,
         src: InFile<Self>,
     ) -> Option<Self::ID> {
-        let kind = MacroDefKind::Declarative;
+        let _p = profile("ast::MacroCall::to_id");
+
+        // Resolve the call's path against the enclosing item's resolver, so
+        // that we point at the macro's actual definition (declarative,
+        // builtin, or attribute/derive) rather than always assuming the call
+        // itself is a `macro_rules!`.
+        if let Some(path) = src.value.path() {
+            let hygiene = Hygiene::new(sb.db, src.file_id);
+            if let Some(path) = ModPath::from_src(path, &hygiene) {
+                let container = sb.find_container(src.as_ref().map(|it| it.syntax()))?;
+                let resolver = sb.resolver_for_container(container);
+                if let Some(def) = resolver.resolve_path_as_macro(sb.db, &path) {
+                    return Some(def);
+                }
+            }
+        }
 
+        // The call didn't resolve to a known definition (eg. it's an
+        // unresolved or not-yet-implemented macro) -- fall back to treating
+        // the call site itself as a `macro_rules!` definition.
         let krate = sb.to_module_def(src.file_id.original_file(sb.db))?.id.krate;
-
         let ast_id =
             Some(AstId::new(src.file_id, sb.db.ast_id_map(src.file_id).ast_id(&src.value)));
-
-        Some(MacroDefId { krate: Some(krate), ast_id, kind })
+        Some(MacroDefId { krate: Some(krate), ast_id, kind: MacroDefKind::Declarative })
     }
 }
 
@@ -320,6 +553,55 @@ impl ToDef for ast::TypeParam {
     }
 }
 
+impl ToDef for ast::LifetimeParam {
+    type Def = LifetimeParam;
+
+    fn to_def<DB: HirDatabase>(
+        sb: &mut SourceBinder<'_, DB>,
+        src: InFile<ast::LifetimeParam>,
+    ) -> Option<LifetimeParam> {
+        let file_id = src.file_id;
+        let parent: GenericDefId = src.value.syntax().ancestors().find_map(|it| {
+            let res = match_ast! {
+                match it {
+                    ast::FnDef(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    ast::StructDef(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    ast::EnumDef(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    ast::TraitDef(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    ast::TypeAliasDef(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    ast::ImplBlock(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    _ => return None,
+                }
+            };
+            Some(res)
+        })?;
+        let &id = sb.child_by_source(parent.into())[keys::LIFETIME_PARAM].get(&src)?;
+        Some(LifetimeParam { id })
+    }
+}
+
+impl ToDef for ast::Label {
+    type Def = Label;
+
+    fn to_def<DB: HirDatabase>(sb: &mut SourceBinder<'_, DB>, src: InFile<Self>) -> Option<Label> {
+        let file_id = src.file_id;
+        let parent: DefWithBodyId = src.value.syntax().ancestors().find_map(|it| {
+            let res = match_ast! {
+                match it {
+                    ast::ConstDef(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    ast::StaticDef(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    ast::FnDef(value) => { sb.to_id(InFile { value, file_id})?.into() },
+                    _ => return None,
+                }
+            };
+            Some(res)
+        })?;
+        let (_body, source_map) = sb.db.body_with_source_map(parent);
+        let label_id = source_map.node_label(src.as_ref())?;
+        Some(Label { parent, label_id })
+    }
+}
+
 impl ToId for ast::Module {
     type ID = ModuleId;
 
@@ -355,3 +637,80 @@ impl ToId for ast::Module {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ra_db::fixture::WithFixture;
+    use ra_syntax::algo::find_node_at_offset;
+
+    use crate::mock::MockDatabase;
+
+    use super::*;
+
+    #[test]
+    fn container_def_finds_nested_fn_through_module() {
+        let (db, position) = MockDatabase::with_position(
+            r#"
+mod outer {
+    fn f() {
+        fn inner() {
+            let x<|> = 1;
+        }
+    }
+}
+"#,
+        );
+        let file = db.parse(position.file_id).tree();
+        let name = find_node_at_offset::<ast::Name>(file.syntax(), position.offset).unwrap();
+
+        let mut sb = SourceBinder::new(&db);
+        let src = InFile { file_id: position.file_id.into(), value: name.syntax() };
+        let def = sb.container_def(src).unwrap();
+
+        assert!(matches!(def, ContainerDef::DefWithBody(DefWithBody::Function(_))));
+    }
+
+    #[test]
+    fn analyze_file_visits_nested_items_top_down() {
+        let (db, position) = MockDatabase::with_position(
+            r#"
+mod outer {
+    fn f() {<|>}
+}
+"#,
+        );
+        let mut sb = SourceBinder::new(&db);
+        let found_fn = sb
+            .analyze_file(position.file_id)
+            .any(|(_src, def)| matches!(def, ContainerDef::DefWithBody(DefWithBody::Function(_))));
+
+        assert!(found_fn);
+    }
+
+    #[test]
+    fn macro_call_to_id_resolves_declarative_macro_definition() {
+        let (db, position) = MockDatabase::with_position(
+            r#"
+macro_rules! foo {
+    () => {};
+}
+
+fn f() {
+    fo<|>o!();
+}
+"#,
+        );
+        let file = db.parse(position.file_id).tree();
+        let call = find_node_at_offset::<ast::MacroCall>(file.syntax(), position.offset).unwrap();
+
+        let mut sb = SourceBinder::new(&db);
+        let src = InFile { file_id: position.file_id.into(), value: call.clone() };
+        let def = sb.to_id(src).unwrap();
+
+        // The call resolves to the `macro_rules!` definition site, not back
+        // to the call expression itself.
+        let ast_id = def.ast_id.expect("resolved macro should carry an ast_id");
+        let resolved = ast_id.to_node(&db);
+        assert_ne!(resolved.syntax().text_range(), call.syntax().text_range());
+    }
+}